@@ -15,8 +15,11 @@
 //! println!("Loaded config for: {}", config.environment);
 //! ```
 
-use serde::Deserialize;
+use directories::ProjectDirs;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::env;
+use std::fmt;
 use std::fs;
 use std::path::{Path, PathBuf};
 use thiserror::Error;
@@ -26,57 +29,439 @@ use thiserror::Error;
 // ============================================================================
 
 /// Main application configuration
-#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 pub struct Config {
     /// Application name
+    #[serde(default = "default_app_name")]
     pub app_name: String,
 
     /// Current environment (production, test, development)
-    pub environment: String,
+    #[serde(default = "default_environment")]
+    pub environment: Environment,
 
     /// Enable debug mode
+    #[serde(default = "default_debug")]
     pub debug: bool,
 
     /// Logging level (debug, info, warn, error)
-    pub log_level: String,
+    #[serde(default = "default_log_level")]
+    pub log_level: LogLevel,
 
     /// Database configuration
     pub database: DatabaseConfig,
 
     /// External services configuration
+    #[serde(default)]
     pub services: ServicesConfig,
 }
 
+impl Config {
+    /// Returns a `Config` with every field set to its built-in default.
+    ///
+    /// Database credentials have no sensible default, so they're filled in
+    /// with empty placeholders here - unlike a loaded file, this constructor
+    /// is never mistaken for a real, validated configuration.
+    pub fn with_defaults() -> Self {
+        Config {
+            app_name: default_app_name(),
+            environment: default_environment(),
+            debug: default_debug(),
+            log_level: default_log_level(),
+            database: DatabaseConfig {
+                host: default_host(),
+                port: default_port(),
+                name: default_db_name(),
+                pool_size: default_pool_size(),
+                ssl_mode: default_ssl_mode(),
+                username: String::new(),
+                password: Secret(String::new()),
+            },
+            services: ServicesConfig::default(),
+        }
+    }
+}
+
 /// Database connection configuration
-#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 pub struct DatabaseConfig {
     /// Database host
+    #[serde(default = "default_host")]
     pub host: String,
 
     /// Database port
+    #[serde(default = "default_port")]
     pub port: u16,
 
     /// Database name
+    #[serde(default = "default_db_name")]
     pub name: String,
 
     /// Connection pool size
+    #[serde(default = "default_pool_size")]
     pub pool_size: u32,
 
     /// SSL mode (disable, prefer, require)
-    pub ssl_mode: String,
+    #[serde(default = "default_ssl_mode")]
+    pub ssl_mode: SslMode,
+
+    /// Database username - required; there is no safe default for credentials
+    pub username: String,
+
+    /// Database password - required; there is no safe default for credentials
+    pub password: Secret,
 }
 
 /// External services configuration
-#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 pub struct ServicesConfig {
     /// API endpoint URL
+    #[serde(default = "default_api_url")]
     pub api_url: String,
 
     /// Cache service URL
+    #[serde(default = "default_cache_url")]
     pub cache_url: String,
 
     /// Request timeout in seconds
+    #[serde(default = "default_timeout_seconds")]
     pub timeout_seconds: u32,
+
+    /// API token, if the service requires one
+    #[serde(default)]
+    pub api_token: Option<Secret>,
+}
+
+impl Default for ServicesConfig {
+    fn default() -> Self {
+        ServicesConfig {
+            api_url: default_api_url(),
+            cache_url: default_cache_url(),
+            timeout_seconds: default_timeout_seconds(),
+            api_token: None,
+        }
+    }
+}
+
+fn default_app_name() -> String {
+    "app".to_string()
+}
+
+fn default_environment() -> Environment {
+    Environment::Development
+}
+
+fn default_debug() -> bool {
+    false
+}
+
+fn default_log_level() -> LogLevel {
+    LogLevel::Info
+}
+
+fn default_host() -> String {
+    "localhost".to_string()
+}
+
+fn default_port() -> u16 {
+    5432
+}
+
+fn default_db_name() -> String {
+    "app".to_string()
+}
+
+fn default_pool_size() -> u32 {
+    10
+}
+
+fn default_ssl_mode() -> SslMode {
+    SslMode::Prefer
+}
+
+fn default_api_url() -> String {
+    "http://localhost:3000".to_string()
+}
+
+fn default_cache_url() -> String {
+    "redis://localhost:6379".to_string()
+}
+
+fn default_timeout_seconds() -> u32 {
+    30
+}
+
+/// Wraps a sensitive string so it can't accidentally leak through `Debug` or
+/// `Display` output (logs, panics, `{:?}` in error messages, etc).
+///
+/// The inner value is only reachable through [`Secret::expose`].
+#[derive(Clone, PartialEq, Deserialize)]
+#[serde(transparent)]
+pub struct Secret(String);
+
+impl Secret {
+    /// Returns the wrapped secret value.
+    ///
+    /// # Safety Check
+    ///
+    /// Only call this where the value is actually needed (e.g. building a
+    /// connection string) - never to log or display it.
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Debug for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[REDACTED]")
+    }
+}
+
+impl fmt::Display for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[REDACTED]")
+    }
+}
+
+impl Serialize for Secret {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        // Re-serializing a loaded config (e.g. for `ConfigWithSources::describe`)
+        // must never round-trip the real secret back out.
+        serializer.serialize_str("[REDACTED]")
+    }
+}
+
+/// Where a single configuration leaf value came from.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ValueSource {
+    /// Read from a configuration file on disk.
+    File(PathBuf),
+    /// Overridden by the named `APP__`-prefixed environment variable.
+    Env(String),
+    /// Fell back to a built-in default.
+    Default,
+}
+
+impl fmt::Display for ValueSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValueSource::File(path) => write!(f, "file: {}", path.display()),
+            ValueSource::Env(var) => write!(f, "env: {}", var),
+            ValueSource::Default => write!(f, "default"),
+        }
+    }
+}
+
+/// A loaded [`Config`] paired with the provenance of each field, keyed by
+/// dotted field path (e.g. `"database.port"`).
+#[derive(Debug, Clone)]
+pub struct ConfigWithSources {
+    /// The fully merged configuration
+    pub config: Config,
+
+    /// Where each leaf field's value came from
+    pub sources: HashMap<String, ValueSource>,
+}
+
+impl ConfigWithSources {
+    /// Renders a sorted `field = value (source)` report, useful for debugging
+    /// "why is this setting what it is".
+    pub fn describe(&self) -> String {
+        let mut fields: Vec<&String> = self.sources.keys().collect();
+        fields.sort();
+
+        fields
+            .into_iter()
+            .map(|field| {
+                let value = describe_field_value(&self.config, field);
+                format!("{} = {} ({})", field, value, self.sources[field])
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// The environment an application instance is running in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Environment {
+    /// Production environment
+    Production,
+    /// Test environment
+    Test,
+    /// Development environment
+    Development,
+}
+
+impl std::str::FromStr for Environment {
+    type Err = ConfigError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "production" => Ok(Environment::Production),
+            "test" => Ok(Environment::Test),
+            "development" => Ok(Environment::Development),
+            other => Err(ConfigError::InvalidValue {
+                field: "environment".to_string(),
+                value: other.to_string(),
+                allowed: "production, test, development".to_string(),
+            }),
+        }
+    }
+}
+
+impl fmt::Display for Environment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Environment::Production => "production",
+            Environment::Test => "test",
+            Environment::Development => "development",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+impl<'de> Deserialize<'de> for Environment {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+impl Serialize for Environment {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+/// Logging verbosity level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogLevel {
+    /// Debug level
+    Debug,
+    /// Info level
+    Info,
+    /// Warn level
+    Warn,
+    /// Error level
+    Error,
+}
+
+impl std::str::FromStr for LogLevel {
+    type Err = ConfigError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "debug" => Ok(LogLevel::Debug),
+            "info" => Ok(LogLevel::Info),
+            "warn" => Ok(LogLevel::Warn),
+            "error" => Ok(LogLevel::Error),
+            other => Err(ConfigError::InvalidValue {
+                field: "log_level".to_string(),
+                value: other.to_string(),
+                allowed: "debug, info, warn, error".to_string(),
+            }),
+        }
+    }
+}
+
+impl fmt::Display for LogLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            LogLevel::Debug => "debug",
+            LogLevel::Info => "info",
+            LogLevel::Warn => "warn",
+            LogLevel::Error => "error",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+impl<'de> Deserialize<'de> for LogLevel {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+impl Serialize for LogLevel {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+/// PostgreSQL-style SSL negotiation mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SslMode {
+    /// Disable SSL
+    Disable,
+    /// Prefer SSL, but allow falling back to an unencrypted connection
+    Prefer,
+    /// Require SSL
+    Require,
+}
+
+impl std::str::FromStr for SslMode {
+    type Err = ConfigError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "disable" => Ok(SslMode::Disable),
+            "prefer" => Ok(SslMode::Prefer),
+            "require" => Ok(SslMode::Require),
+            other => Err(ConfigError::InvalidValue {
+                field: "ssl_mode".to_string(),
+                value: other.to_string(),
+                allowed: "disable, prefer, require".to_string(),
+            }),
+        }
+    }
+}
+
+impl fmt::Display for SslMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            SslMode::Disable => "disable",
+            SslMode::Prefer => "prefer",
+            SslMode::Require => "require",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+impl<'de> Deserialize<'de> for SslMode {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+impl Serialize for SslMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
 }
 
 // ============================================================================
@@ -97,25 +482,39 @@ pub enum ConfigError {
         source: std::io::Error,
     },
 
-    /// Failed to parse YAML configuration
+    /// Failed to parse configuration (YAML or TOML)
     #[error("Failed to parse configuration: {source}")]
     ParseError {
         #[from]
-        source: serde_yaml::Error,
+        source: ConfigFormatError,
     },
 
-    /// Invalid environment specified
-    #[error("Invalid environment: {env}. Valid options: production, test, development")]
-    InvalidEnvironment { env: String },
+    /// A field held a value outside its allowed set (e.g. a misspelled
+    /// `ssl_mode` or `environment`)
+    #[error("Invalid value for {field}: {value:?}. Allowed values: {allowed}")]
+    InvalidValue {
+        field: String,
+        value: String,
+        allowed: String,
+    },
+}
+
+/// The underlying format-specific parse failure behind [`ConfigError::ParseError`].
+#[derive(Error, Debug)]
+pub enum ConfigFormatError {
+    /// Failed to parse a YAML configuration file
+    #[error(transparent)]
+    Yaml(#[from] serde_yaml::Error),
+
+    /// Failed to parse a TOML configuration file
+    #[error(transparent)]
+    Toml(#[from] toml::de::Error),
 }
 
 // ============================================================================
 // PRODUCTION CODE - Functions
 // ============================================================================
 
-/// Valid environment names
-const VALID_ENVIRONMENTS: [&str; 3] = ["production", "test", "development"];
-
 /// Loads configuration based on the APP_ENV environment variable.
 ///
 /// # Arguments
@@ -142,7 +541,7 @@ const VALID_ENVIRONMENTS: [&str; 3] = ["production", "test", "development"];
 /// ```
 pub fn load_config(config_dir: Option<&Path>) -> Result<Config, ConfigError> {
     let environment = get_environment()?;
-    load_config_for_env(&environment, config_dir)
+    load_config_with_overrides(&environment, config_dir)
 }
 
 /// Loads configuration for a specific environment.
@@ -171,11 +570,280 @@ pub fn load_config_for_env(
     }
 
     let contents = fs::read_to_string(&config_path)?;
-    let config: Config = serde_yaml::from_str(&contents)?;
+    let value = parse_config_value(&contents, &config_path)?;
+    let config: Config =
+        serde_yaml::from_value(value).map_err(|source| ConfigError::ParseError {
+            source: ConfigFormatError::Yaml(source),
+        })?;
 
     Ok(config)
 }
 
+/// Loads configuration for a specific environment, then layers `APP__`-prefixed
+/// environment variable overrides on top of it.
+///
+/// # Override Convention
+///
+/// An override variable must be uppercase, start with `APP__`, and use `__` to
+/// separate path segments that map onto the nested `Config` struct, e.g.:
+///
+/// * `APP__DATABASE__PORT=5999` overrides `database.port`
+/// * `APP__SERVICES__TIMEOUT_SECONDS=60` overrides `services.timeout_seconds`
+/// * `APP__LOG_LEVEL=warn` overrides `log_level`
+///
+/// Each override is parsed to match the type of the value already present at
+/// that path where possible; a value that cannot be reconciled with the rest
+/// of the config surfaces as a `ConfigError::ParseError` once the merged
+/// document is deserialized.
+///
+/// # Arguments
+///
+/// * `environment` - The environment name (production, test, development)
+/// * `config_dir` - Optional custom config directory path
+///
+/// # Returns
+///
+/// * `Ok(Config)` - Successfully loaded configuration with overrides applied
+/// * `Err(ConfigError)` - Failed to load or merge configuration
+pub fn load_config_with_overrides(
+    environment: &str,
+    config_dir: Option<&Path>,
+) -> Result<Config, ConfigError> {
+    validate_environment(environment)?;
+
+    let config_path = get_config_path(environment, config_dir);
+
+    if !config_path.exists() {
+        return Err(ConfigError::NotFound {
+            path: config_path.display().to_string(),
+        });
+    }
+
+    let contents = fs::read_to_string(&config_path)?;
+    let mut value = parse_config_value(&contents, &config_path)?;
+
+    apply_env_overrides(&mut value);
+
+    let config: Config =
+        serde_yaml::from_value(value).map_err(|source| ConfigError::ParseError {
+            source: ConfigFormatError::Yaml(source),
+        })?;
+    Ok(config)
+}
+
+/// Walks every `APP__`-prefixed environment variable and merges it into `value`.
+fn apply_env_overrides(value: &mut serde_yaml::Value) {
+    for (key, raw) in env::vars() {
+        if key != key.to_uppercase() {
+            continue;
+        }
+        if let Some(rest) = key.strip_prefix("APP__") {
+            let path: Vec<String> = rest.split("__").map(|s| s.to_lowercase()).collect();
+            set_override(value, &path, &raw);
+        }
+    }
+}
+
+/// Sets a single override at `path` within `value`, creating intermediate
+/// mappings as needed and coercing `raw` to match the existing leaf's type.
+fn set_override(value: &mut serde_yaml::Value, path: &[String], raw: &str) {
+    if path.is_empty() {
+        return;
+    }
+
+    if !value.is_mapping() {
+        *value = serde_yaml::Value::Mapping(serde_yaml::Mapping::new());
+    }
+    let mapping = value
+        .as_mapping_mut()
+        .expect("value coerced to mapping above");
+    let key = serde_yaml::Value::String(path[0].clone());
+
+    if path.len() == 1 {
+        let new_value = coerce_override(raw, mapping.get(&key));
+        mapping.insert(key, new_value);
+        return;
+    }
+
+    let child = mapping
+        .entry(key)
+        .or_insert_with(|| serde_yaml::Value::Mapping(serde_yaml::Mapping::new()));
+    set_override(child, &path[1..], raw);
+}
+
+/// Parses `raw` to match the type of `existing`, falling back to a plain
+/// string when there is no existing value or the type can't be matched.
+fn coerce_override(raw: &str, existing: Option<&serde_yaml::Value>) -> serde_yaml::Value {
+    match existing {
+        Some(serde_yaml::Value::Bool(_)) => raw
+            .parse::<bool>()
+            .map(serde_yaml::Value::Bool)
+            .unwrap_or_else(|_| serde_yaml::Value::String(raw.to_string())),
+        Some(serde_yaml::Value::Number(n)) if n.is_i64() || n.is_u64() => raw
+            .parse::<i64>()
+            .map(|parsed| serde_yaml::Value::Number(parsed.into()))
+            .unwrap_or_else(|_| serde_yaml::Value::String(raw.to_string())),
+        Some(serde_yaml::Value::Number(_)) => raw
+            .parse::<f64>()
+            .map(|parsed| serde_yaml::Value::Number(parsed.into()))
+            .unwrap_or_else(|_| serde_yaml::Value::String(raw.to_string())),
+        _ => serde_yaml::Value::String(raw.to_string()),
+    }
+}
+
+/// Loads configuration for a specific environment like [`load_config_with_overrides`],
+/// but also returns the provenance of every leaf field - the config file it was
+/// read from, the environment variable that overrode it, or a built-in default.
+///
+/// # Arguments
+///
+/// * `environment` - The environment name (production, test, development)
+/// * `config_dir` - Optional custom config directory path
+///
+/// # Returns
+///
+/// * `Ok(ConfigWithSources)` - The merged configuration and its field provenance
+/// * `Err(ConfigError)` - Failed to load or merge configuration
+pub fn load_config_traced(
+    environment: &str,
+    config_dir: Option<&Path>,
+) -> Result<ConfigWithSources, ConfigError> {
+    validate_environment(environment)?;
+
+    let config_path = get_config_path(environment, config_dir);
+
+    if !config_path.exists() {
+        return Err(ConfigError::NotFound {
+            path: config_path.display().to_string(),
+        });
+    }
+
+    let contents = fs::read_to_string(&config_path)?;
+    let mut value = parse_config_value(&contents, &config_path)?;
+
+    let mut sources = HashMap::new();
+    collect_sources(&value, "", &ValueSource::File(config_path), &mut sources);
+    apply_env_overrides_traced(&mut value, &mut sources);
+
+    let config: Config =
+        serde_yaml::from_value(value).map_err(|source| ConfigError::ParseError {
+            source: ConfigFormatError::Yaml(source),
+        })?;
+
+    // Any leaf present in the fully-merged config but not already attributed
+    // to a file or env override fell back to a built-in `#[serde(default)]`.
+    if let Ok(full_value) = serde_yaml::to_value(&config) {
+        record_default_sources(&full_value, "", &mut sources);
+    }
+
+    Ok(ConfigWithSources { config, sources })
+}
+
+/// Records `source` for every scalar leaf found under `value`, keyed by its
+/// dotted field path.
+fn collect_sources(
+    value: &serde_yaml::Value,
+    prefix: &str,
+    source: &ValueSource,
+    into: &mut HashMap<String, ValueSource>,
+) {
+    match value.as_mapping() {
+        Some(mapping) => {
+            for (key, child) in mapping {
+                let key_str = key.as_str().unwrap_or_default();
+                let path = if prefix.is_empty() {
+                    key_str.to_string()
+                } else {
+                    format!("{}.{}", prefix, key_str)
+                };
+                collect_sources(child, &path, source, into);
+            }
+        }
+        None => {
+            if !prefix.is_empty() {
+                into.insert(prefix.to_string(), source.clone());
+            }
+        }
+    }
+}
+
+/// Walks every leaf in `value` and records `ValueSource::Default` for any
+/// path not already present in `sources` - i.e. a field that wasn't in the
+/// file or overridden by an env var, so it must have come from its
+/// `#[serde(default)]`.
+fn record_default_sources(
+    value: &serde_yaml::Value,
+    prefix: &str,
+    sources: &mut HashMap<String, ValueSource>,
+) {
+    match value.as_mapping() {
+        Some(mapping) => {
+            for (key, child) in mapping {
+                let key_str = key.as_str().unwrap_or_default();
+                let path = if prefix.is_empty() {
+                    key_str.to_string()
+                } else {
+                    format!("{}.{}", prefix, key_str)
+                };
+                record_default_sources(child, &path, sources);
+            }
+        }
+        None => {
+            if !prefix.is_empty() {
+                sources
+                    .entry(prefix.to_string())
+                    .or_insert(ValueSource::Default);
+            }
+        }
+    }
+}
+
+/// Like [`apply_env_overrides`], but also records each override's source.
+fn apply_env_overrides_traced(
+    value: &mut serde_yaml::Value,
+    sources: &mut HashMap<String, ValueSource>,
+) {
+    for (key, raw) in env::vars() {
+        if key != key.to_uppercase() {
+            continue;
+        }
+        if let Some(rest) = key.strip_prefix("APP__") {
+            let path: Vec<String> = rest.split("__").map(|s| s.to_lowercase()).collect();
+            set_override(value, &path, &raw);
+            sources.insert(path.join("."), ValueSource::Env(key));
+        }
+    }
+}
+
+/// Looks up the rendered value of `field` (a dotted path) within `config`.
+fn describe_field_value(config: &Config, field: &str) -> String {
+    let Ok(value) = serde_yaml::to_value(config) else {
+        return "?".to_string();
+    };
+
+    let mut current = &value;
+    for segment in field.split('.') {
+        let Some(child) = current
+            .as_mapping()
+            .and_then(|m| m.get(serde_yaml::Value::String(segment.to_string())))
+        else {
+            return "?".to_string();
+        };
+        current = child;
+    }
+
+    match current {
+        serde_yaml::Value::String(s) => s.clone(),
+        serde_yaml::Value::Bool(b) => b.to_string(),
+        serde_yaml::Value::Number(n) => n.to_string(),
+        serde_yaml::Value::Null => "null".to_string(),
+        other => serde_yaml::to_string(other)
+            .unwrap_or_default()
+            .trim()
+            .to_string(),
+    }
+}
+
 /// Gets the current environment from APP_ENV, defaulting to "development".
 ///
 /// # Returns
@@ -190,19 +858,83 @@ pub fn get_environment() -> Result<String, ConfigError> {
 
 /// Validates that an environment name is valid.
 fn validate_environment(environment: &str) -> Result<(), ConfigError> {
-    if VALID_ENVIRONMENTS.contains(&environment) {
-        Ok(())
-    } else {
-        Err(ConfigError::InvalidEnvironment {
-            env: environment.to_string(),
-        })
-    }
+    environment.parse::<Environment>().map(|_| ())
 }
 
-/// Constructs the path to a configuration file.
+/// File extensions searched for a configuration file, in priority order.
+const CONFIG_EXTENSIONS: [&str; 3] = ["yaml", "yml", "toml"];
+
+/// Constructs the path to a configuration file, searching a prioritized list
+/// of `(directory, extension)` candidates and returning the first that
+/// exists. Falls back to `<config_dir_or_default>/<environment>.yaml` (which
+/// may not exist) so callers still get a sensible path for `NotFound` errors.
 fn get_config_path(environment: &str, config_dir: Option<&Path>) -> PathBuf {
-    let base_dir = config_dir.unwrap_or_else(|| Path::new("config"));
-    base_dir.join(format!("{}.yaml", environment))
+    candidate_config_paths(environment, config_dir)
+        .into_iter()
+        .find(|path| path.exists())
+        .unwrap_or_else(|| {
+            let base_dir = config_dir
+                .map(Path::to_path_buf)
+                .unwrap_or_else(|| PathBuf::from("config"));
+            base_dir.join(format!("{}.yaml", environment))
+        })
+}
+
+/// Builds the prioritized list of candidate config file paths for `environment`.
+///
+/// When `config_dir` is given, only that directory is searched. Otherwise
+/// `./config` is preferred, falling back to the platform config directory
+/// (e.g. `~/.config/guardrails-config-example/` on Linux) via the
+/// `directories` crate.
+fn candidate_config_paths(environment: &str, config_dir: Option<&Path>) -> Vec<PathBuf> {
+    let dir = match config_dir {
+        Some(dir) => dir.to_path_buf(),
+        None => {
+            let default_dir = PathBuf::from("config");
+            if default_dir.exists() {
+                default_dir
+            } else if let Some(project_dirs) =
+                ProjectDirs::from("", "", "guardrails-config-example")
+            {
+                project_dirs.config_dir().to_path_buf()
+            } else {
+                default_dir
+            }
+        }
+    };
+
+    CONFIG_EXTENSIONS
+        .iter()
+        .map(|ext| dir.join(format!("{}.{}", environment, ext)))
+        .collect()
+}
+
+/// Parses `contents` as YAML or TOML based on `config_path`'s extension, into
+/// a `serde_yaml::Value` so the rest of the loading pipeline (env var
+/// overrides, provenance tracking) stays format-agnostic.
+fn parse_config_value(
+    contents: &str,
+    config_path: &Path,
+) -> Result<serde_yaml::Value, ConfigError> {
+    let extension = config_path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .unwrap_or("yaml");
+
+    match extension {
+        "toml" => {
+            let toml_value: toml::Value =
+                toml::from_str(contents).map_err(|source| ConfigError::ParseError {
+                    source: ConfigFormatError::Toml(source),
+                })?;
+            serde_yaml::to_value(toml_value).map_err(|source| ConfigError::ParseError {
+                source: ConfigFormatError::Yaml(source),
+            })
+        }
+        _ => serde_yaml::from_str(contents).map_err(|source| ConfigError::ParseError {
+            source: ConfigFormatError::Yaml(source),
+        }),
+    }
 }
 
 /// Checks if the current configuration is for a test environment.
@@ -211,7 +943,7 @@ fn get_config_path(environment: &str, config_dir: Option<&Path>) -> PathBuf {
 ///
 /// This function helps prevent accidental use of production resources in tests.
 pub fn is_test_environment(config: &Config) -> bool {
-    config.environment == "test"
+    config.environment == Environment::Test
 }
 
 /// Checks if the current configuration is for production.
@@ -220,7 +952,7 @@ pub fn is_test_environment(config: &Config) -> bool {
 ///
 /// Use this to add extra safeguards around production operations.
 pub fn is_production_environment(config: &Config) -> bool {
-    config.environment == "production"
+    config.environment == Environment::Production
 }
 
 // ============================================================================
@@ -231,8 +963,16 @@ pub fn is_production_environment(config: &Config) -> bool {
 mod tests {
     use super::*;
     use std::io::Write;
+    use std::sync::Mutex;
     use tempfile::TempDir;
 
+    /// `apply_env_overrides`/`apply_env_overrides_traced` scan the whole
+    /// process environment for `APP__`-prefixed vars, and `HOME` affects every
+    /// XDG fallback path. Tests that set, remove, or rely on the absence of
+    /// these process-wide vars must not run concurrently with each other, so
+    /// they all acquire this guard first.
+    static ENV_TEST_GUARD: Mutex<()> = Mutex::new(());
+
     /// Helper: Creates a temporary config directory with test configs
     fn create_test_config_dir() -> TempDir {
         let temp_dir = TempDir::new().expect("Failed to create temp dir");
@@ -250,11 +990,14 @@ database:
   name: "app_test"
   pool_size: 2
   ssl_mode: "disable"
+  username: "test_user"
+  password: "test_password"
 
 services:
   api_url: "http://localhost:8080"
   cache_url: "redis://localhost:6380"
   timeout_seconds: 5
+  api_token: "test-token"
 "#;
 
         let test_path = temp_dir.path().join("test.yaml");
@@ -275,11 +1018,14 @@ database:
   name: "app_development"
   pool_size: 5
   ssl_mode: "prefer"
+  username: "dev_user"
+  password: "dev_password"
 
 services:
   api_url: "http://localhost:3000"
   cache_url: "redis://localhost:6379"
   timeout_seconds: 10
+  api_token: null
 "#;
 
         let dev_path = temp_dir.path().join("development.yaml");
@@ -300,11 +1046,14 @@ database:
   name: "app_production"
   pool_size: 20
   ssl_mode: "require"
+  username: "prod_user"
+  password: "prod_password"
 
 services:
   api_url: "https://api.example.com"
   cache_url: "redis://prod-cache.example.com:6379"
   timeout_seconds: 30
+  api_token: "prod-secret-token"
 "#;
 
         let prod_path = temp_dir.path().join("production.yaml");
@@ -327,9 +1076,9 @@ services:
             load_config_for_env("test", Some(temp_dir.path())).expect("Failed to load test config");
 
         assert_eq!(config.app_name, "test-app");
-        assert_eq!(config.environment, "test");
+        assert_eq!(config.environment, Environment::Test);
         assert!(config.debug);
-        assert_eq!(config.log_level, "debug");
+        assert_eq!(config.log_level, LogLevel::Debug);
     }
 
     #[test]
@@ -340,7 +1089,7 @@ services:
             .expect("Failed to load dev config");
 
         assert_eq!(config.app_name, "dev-app");
-        assert_eq!(config.environment, "development");
+        assert_eq!(config.environment, Environment::Development);
         assert!(config.debug);
     }
 
@@ -352,9 +1101,9 @@ services:
             .expect("Failed to load prod config");
 
         assert_eq!(config.app_name, "prod-app");
-        assert_eq!(config.environment, "production");
+        assert_eq!(config.environment, Environment::Production);
         assert!(!config.debug);
-        assert_eq!(config.log_level, "info");
+        assert_eq!(config.log_level, LogLevel::Info);
     }
 
     // -------------------------------------------------------------------------
@@ -372,7 +1121,21 @@ services:
         assert_eq!(config.database.port, 5433);
         assert_eq!(config.database.name, "app_test");
         assert_eq!(config.database.pool_size, 2);
-        assert_eq!(config.database.ssl_mode, "disable");
+        assert_eq!(config.database.ssl_mode, SslMode::Disable);
+        assert_eq!(config.database.username, "test_user");
+        assert_eq!(config.database.password.expose(), "test_password");
+    }
+
+    #[test]
+    fn test_database_password_not_in_debug_output() {
+        let temp_dir = create_test_config_dir();
+
+        let config =
+            load_config_for_env("test", Some(temp_dir.path())).expect("Failed to load config");
+
+        let debug_output = format!("{:?}", config);
+        assert!(!debug_output.contains("test_password"));
+        assert!(debug_output.contains("[REDACTED]"));
     }
 
     #[test]
@@ -405,6 +1168,20 @@ services:
         assert_eq!(config.services.api_url, "http://localhost:8080");
         assert_eq!(config.services.cache_url, "redis://localhost:6380");
         assert_eq!(config.services.timeout_seconds, 5);
+        assert_eq!(
+            config.services.api_token.as_ref().map(Secret::expose),
+            Some("test-token")
+        );
+    }
+
+    #[test]
+    fn test_services_api_token_optional() {
+        let temp_dir = create_test_config_dir();
+
+        let config = load_config_for_env("development", Some(temp_dir.path()))
+            .expect("Failed to load dev config");
+
+        assert!(config.services.api_token.is_none());
     }
 
     #[test]
@@ -437,7 +1214,7 @@ services:
 
         assert!(result.is_err());
         let err = result.unwrap_err();
-        assert!(matches!(err, ConfigError::InvalidEnvironment { .. }));
+        assert!(matches!(err, ConfigError::InvalidValue { .. }));
     }
 
     #[test]
@@ -470,6 +1247,60 @@ services:
         assert!(matches!(err, ConfigError::ParseError { .. }));
     }
 
+    #[test]
+    fn test_invalid_toml_error() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+
+        let invalid_toml = "this = is not [valid toml";
+        let test_path = temp_dir.path().join("test.toml");
+        fs::write(&test_path, invalid_toml).expect("Failed to write");
+
+        let result = load_config_for_env("test", Some(temp_dir.path()));
+
+        assert!(result.is_err());
+        let err = result.unwrap_err();
+        assert!(matches!(err, ConfigError::ParseError { .. }));
+    }
+
+    // -------------------------------------------------------------------------
+    // TOML Config Tests
+    // -------------------------------------------------------------------------
+
+    #[test]
+    fn test_load_toml_config() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+
+        let toml_config = r#"
+app_name = "toml-app"
+environment = "test"
+debug = true
+log_level = "debug"
+
+[database]
+host = "localhost"
+port = 5433
+name = "app_test"
+pool_size = 2
+ssl_mode = "disable"
+username = "test_user"
+password = "test_password"
+
+[services]
+api_url = "http://localhost:8080"
+cache_url = "redis://localhost:6380"
+timeout_seconds = 5
+"#;
+        let test_path = temp_dir.path().join("test.toml");
+        fs::write(&test_path, toml_config).expect("Failed to write toml config");
+
+        let config =
+            load_config_for_env("test", Some(temp_dir.path())).expect("Failed to load toml config");
+
+        assert_eq!(config.app_name, "toml-app");
+        assert_eq!(config.database.port, 5433);
+        assert_eq!(config.database.password.expose(), "test_password");
+    }
+
     // -------------------------------------------------------------------------
     // Environment Helper Tests
     // -------------------------------------------------------------------------
@@ -521,6 +1352,48 @@ services:
         assert_eq!(result, PathBuf::from("config/production.yaml"));
     }
 
+    #[test]
+    fn test_get_config_path_prefers_existing_toml_file() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let toml_path = temp_dir.path().join("test.toml");
+        fs::write(&toml_path, "app_name = \"toml-app\"").expect("Failed to write toml config");
+
+        let result = get_config_path("test", Some(temp_dir.path()));
+
+        assert_eq!(result, toml_path);
+    }
+
+    #[test]
+    fn test_get_config_path_accepts_yml_alias() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let yml_path = temp_dir.path().join("test.yml");
+        fs::write(&yml_path, "app_name: \"yml-app\"").expect("Failed to write yml config");
+
+        let result = get_config_path("test", Some(temp_dir.path()));
+
+        assert_eq!(result, yml_path);
+    }
+
+    #[test]
+    fn test_candidate_paths_fall_back_to_xdg_config_dir() {
+        let _guard = ENV_TEST_GUARD.lock().unwrap_or_else(|e| e.into_inner());
+
+        let fake_home = TempDir::new().expect("Failed to create temp dir");
+        let previous_home = env::var("HOME").ok();
+        env::set_var("HOME", fake_home.path());
+
+        let candidates = candidate_config_paths("test", None);
+
+        match previous_home {
+            Some(home) => env::set_var("HOME", home),
+            None => env::remove_var("HOME"),
+        }
+
+        assert!(candidates
+            .iter()
+            .all(|path| path.starts_with(fake_home.path())));
+    }
+
     // -------------------------------------------------------------------------
     // Environment Validation Tests
     // -------------------------------------------------------------------------
@@ -539,6 +1412,158 @@ services:
         assert!(validate_environment("").is_err());
     }
 
+    // -------------------------------------------------------------------------
+    // Environment Variable Override Tests
+    // -------------------------------------------------------------------------
+
+    #[test]
+    fn test_env_override_wins_over_file_value() {
+        let _guard = ENV_TEST_GUARD.lock().unwrap_or_else(|e| e.into_inner());
+
+        let temp_dir = create_test_config_dir();
+
+        env::set_var("APP__DATABASE__PORT", "5999");
+        let config = load_config_with_overrides("test", Some(temp_dir.path()));
+        env::remove_var("APP__DATABASE__PORT");
+
+        let config = config.expect("Failed to load config with overrides");
+        assert_eq!(config.database.port, 5999);
+    }
+
+    #[test]
+    fn test_env_override_nested_services_field() {
+        let _guard = ENV_TEST_GUARD.lock().unwrap_or_else(|e| e.into_inner());
+
+        let temp_dir = create_test_config_dir();
+
+        env::set_var("APP__SERVICES__TIMEOUT_SECONDS", "60");
+        let config = load_config_with_overrides("test", Some(temp_dir.path()));
+        env::remove_var("APP__SERVICES__TIMEOUT_SECONDS");
+
+        let config = config.expect("Failed to load config with overrides");
+        assert_eq!(config.services.timeout_seconds, 60);
+    }
+
+    #[test]
+    fn test_env_override_top_level_field() {
+        let _guard = ENV_TEST_GUARD.lock().unwrap_or_else(|e| e.into_inner());
+
+        let temp_dir = create_test_config_dir();
+
+        env::set_var("APP__LOG_LEVEL", "warn");
+        let config = load_config_with_overrides("test", Some(temp_dir.path()));
+        env::remove_var("APP__LOG_LEVEL");
+
+        let config = config.expect("Failed to load config with overrides");
+        assert_eq!(config.log_level, LogLevel::Warn);
+    }
+
+    #[test]
+    fn test_env_override_type_mismatch_is_parse_error() {
+        let _guard = ENV_TEST_GUARD.lock().unwrap_or_else(|e| e.into_inner());
+
+        let temp_dir = create_test_config_dir();
+
+        env::set_var("APP__DATABASE__PORT", "not-a-port");
+        let result = load_config_with_overrides("test", Some(temp_dir.path()));
+        env::remove_var("APP__DATABASE__PORT");
+
+        assert!(result.is_err());
+        assert!(matches!(
+            result.unwrap_err(),
+            ConfigError::ParseError { .. }
+        ));
+    }
+
+    // -------------------------------------------------------------------------
+    // Configuration Provenance Tests
+    // -------------------------------------------------------------------------
+
+    #[test]
+    fn test_traced_file_value_source() {
+        let _guard = ENV_TEST_GUARD.lock().unwrap_or_else(|e| e.into_inner());
+
+        let temp_dir = create_test_config_dir();
+
+        let traced = load_config_traced("test", Some(temp_dir.path()))
+            .expect("Failed to load traced config");
+
+        assert_eq!(traced.config.database.port, 5433);
+        assert!(matches!(
+            traced.sources.get("database.port"),
+            Some(ValueSource::File(_))
+        ));
+    }
+
+    #[test]
+    fn test_traced_env_override_source() {
+        let _guard = ENV_TEST_GUARD.lock().unwrap_or_else(|e| e.into_inner());
+
+        let temp_dir = create_test_config_dir();
+
+        env::set_var("APP__DATABASE__PORT", "5999");
+        let traced = load_config_traced("test", Some(temp_dir.path()));
+        env::remove_var("APP__DATABASE__PORT");
+
+        let traced = traced.expect("Failed to load traced config");
+        assert_eq!(traced.config.database.port, 5999);
+        assert_eq!(
+            traced.sources.get("database.port"),
+            Some(&ValueSource::Env("APP__DATABASE__PORT".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_describe_report_contains_field_and_source() {
+        let _guard = ENV_TEST_GUARD.lock().unwrap_or_else(|e| e.into_inner());
+
+        let temp_dir = create_test_config_dir();
+
+        let traced = load_config_traced("test", Some(temp_dir.path()))
+            .expect("Failed to load traced config");
+
+        let report = traced.describe();
+        assert!(report.contains("database.port = 5433 (file:"));
+    }
+
+    #[test]
+    fn test_traced_records_default_source_for_omitted_fields() {
+        let _guard = ENV_TEST_GUARD.lock().unwrap_or_else(|e| e.into_inner());
+
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+
+        let minimal_config = r#"
+app_name: "minimal-app"
+environment: "test"
+
+database:
+  username: "minimal_user"
+  password: "minimal_password"
+"#;
+        let config_path = temp_dir.path().join("test.yaml");
+        fs::write(&config_path, minimal_config).expect("Failed to write config");
+
+        let traced = load_config_traced("test", Some(temp_dir.path()))
+            .expect("Failed to load traced config");
+
+        assert_eq!(traced.config.database.pool_size, 10);
+        assert_eq!(
+            traced.sources.get("database.pool_size"),
+            Some(&ValueSource::Default)
+        );
+        assert_eq!(traced.sources.get("log_level"), Some(&ValueSource::Default));
+        assert_eq!(
+            traced.sources.get("services.timeout_seconds"),
+            Some(&ValueSource::Default)
+        );
+
+        // Fields actually present in the file should still be attributed to it.
+        assert!(matches!(
+            traced.sources.get("app_name"),
+            Some(ValueSource::File(_))
+        ));
+    }
+
     // -------------------------------------------------------------------------
     // Config Equality Tests
     // -------------------------------------------------------------------------
@@ -567,4 +1592,190 @@ services:
 
         assert_eq!(config, cloned);
     }
+
+    // -------------------------------------------------------------------------
+    // Default Value Tests
+    // -------------------------------------------------------------------------
+
+    #[test]
+    fn test_with_defaults_uses_built_in_defaults() {
+        let config = Config::with_defaults();
+
+        assert_eq!(config.app_name, "app");
+        assert_eq!(config.environment, Environment::Development);
+        assert!(!config.debug);
+        assert_eq!(config.log_level, LogLevel::Info);
+        assert_eq!(config.database.port, 5432);
+        assert_eq!(config.database.pool_size, 10);
+        assert_eq!(config.database.ssl_mode, SslMode::Prefer);
+        assert_eq!(config.services.timeout_seconds, 30);
+        assert!(config.services.api_token.is_none());
+    }
+
+    #[test]
+    fn test_partial_config_falls_back_to_defaults() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+
+        // Credentials are mandatory (no safe default), but everything else
+        // in `database` and `services` is omittable.
+        let minimal_config = r#"
+app_name: "minimal-app"
+environment: "test"
+
+database:
+  username: "minimal_user"
+  password: "minimal_password"
+"#;
+        let config_path = temp_dir.path().join("test.yaml");
+        let mut file = fs::File::create(&config_path).expect("Failed to create config");
+        file.write_all(minimal_config.as_bytes())
+            .expect("Failed to write config");
+
+        let config = load_config_for_env("test", Some(temp_dir.path()))
+            .expect("Failed to load partial config");
+
+        assert_eq!(config.app_name, "minimal-app");
+        assert_eq!(config.environment, Environment::Test);
+        assert!(!config.debug);
+        assert_eq!(config.log_level, LogLevel::Info);
+        assert_eq!(config.database.username, "minimal_user");
+        assert_eq!(config.database.pool_size, 10);
+        assert_eq!(config.database.ssl_mode, SslMode::Prefer);
+        assert_eq!(config.services.timeout_seconds, 30);
+    }
+
+    #[test]
+    fn test_missing_database_credentials_is_parse_error() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+
+        let config_without_credentials = r#"
+app_name: "minimal-app"
+environment: "test"
+
+database:
+  host: "localhost"
+"#;
+        let config_path = temp_dir.path().join("test.yaml");
+        fs::write(&config_path, config_without_credentials).expect("Failed to write config");
+
+        let result = load_config_for_env("test", Some(temp_dir.path()));
+
+        assert!(result.is_err());
+        assert!(matches!(
+            result.unwrap_err(),
+            ConfigError::ParseError { .. }
+        ));
+    }
+
+    #[test]
+    fn test_missing_database_block_is_parse_error() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+
+        let config_without_database = r#"
+app_name: "minimal-app"
+environment: "test"
+"#;
+        let config_path = temp_dir.path().join("test.yaml");
+        fs::write(&config_path, config_without_database).expect("Failed to write config");
+
+        let result = load_config_for_env("test", Some(temp_dir.path()));
+
+        assert!(result.is_err());
+        assert!(matches!(
+            result.unwrap_err(),
+            ConfigError::ParseError { .. }
+        ));
+    }
+
+    // -------------------------------------------------------------------------
+    // Strongly-Typed Field Validation Tests
+    // -------------------------------------------------------------------------
+
+    #[test]
+    fn test_ssl_mode_rejects_unknown_value() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+
+        let config_with_bad_ssl_mode = r#"
+app_name: "test-app"
+environment: "test"
+
+database:
+  host: "localhost"
+  port: 5433
+  name: "app_test"
+  username: "test_user"
+  password: "test_password"
+  ssl_mode: "requir"
+"#;
+        let config_path = temp_dir.path().join("test.yaml");
+        fs::write(&config_path, config_with_bad_ssl_mode).expect("Failed to write config");
+
+        let result = load_config_for_env("test", Some(temp_dir.path()));
+
+        assert!(result.is_err());
+        assert!(matches!(
+            result.unwrap_err(),
+            ConfigError::ParseError { .. }
+        ));
+    }
+
+    #[test]
+    fn test_log_level_rejects_unknown_value() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+
+        let config_with_bad_log_level = r#"
+app_name: "test-app"
+environment: "test"
+log_level: "verbose"
+
+database:
+  username: "test_user"
+  password: "test_password"
+"#;
+        let config_path = temp_dir.path().join("test.yaml");
+        fs::write(&config_path, config_with_bad_log_level).expect("Failed to write config");
+
+        let result = load_config_for_env("test", Some(temp_dir.path()));
+
+        assert!(result.is_err());
+        assert!(matches!(
+            result.unwrap_err(),
+            ConfigError::ParseError { .. }
+        ));
+    }
+
+    #[test]
+    fn test_environment_field_rejects_unknown_value() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+
+        let config_with_bad_environment = r#"
+app_name: "test-app"
+environment: "staging"
+
+database:
+  username: "test_user"
+  password: "test_password"
+"#;
+        let config_path = temp_dir.path().join("test.yaml");
+        fs::write(&config_path, config_with_bad_environment).expect("Failed to write config");
+
+        let result = load_config_for_env("test", Some(temp_dir.path()));
+
+        assert!(result.is_err());
+        assert!(matches!(
+            result.unwrap_err(),
+            ConfigError::ParseError { .. }
+        ));
+    }
+
+    #[test]
+    fn test_ssl_mode_from_str_rejects_unknown_value() {
+        let result = "requir".parse::<SslMode>();
+
+        assert!(result.is_err());
+        assert!(matches!(
+            result.unwrap_err(),
+            ConfigError::InvalidValue { .. }
+        ));
+    }
 }